@@ -1,6 +1,6 @@
 use frame_support::{
     parameter_types,
-    traits::{Get, ProcessMessageError},
+    traits::{Contains, Get, ProcessMessageError},
 };
 use parity_scale_codec::{Compact, Encode};
 use sp_core::{crypto::AccountId32, ConstU32};
@@ -31,59 +31,76 @@ impl<
         max_weight: Weight,
         properties: &mut Properties,
     ) -> Result<(), ProcessMessageError> {
-        let mut actual_origin = *origin;
-        let skipped = Cell::new(0usize);
-        instructions.matcher().match_next_inst_while(
-            |_| skipped.get() < MaxPrefixes::get() as usize,
-            |inst| {
-                match inst {
-                    UniversalOrigin(new_global) => {
-                        // ↓↓ ORIGINAL CODE ↓↓
-
-                        // Note the origin is *relative to local consensus*! So we need to escape
-                        // local consensus with the `parents` before diving in into the
-                        // `universal_location`.
-                        // actual_origin = X1(*new_global).relative_to(&LocalUniversal::get());
-
-                        // ↑↑ ORIGINAL CODE ↑↑
-
-                        // ↓↓ NEW CODE ↓↓
-
-                        actual_origin = X1(GlobalConsensus(
-                            LocalUniversal::get()
-                                .global_consensus()
-                                .map_err(|_| ProcessMessageError::Unsupported)?,
-                        ))
-                        .within_global(
-                            actual_origin
-                                .prepended_with(LocalUniversal::get().relative_to(&X1(*new_global)))
-                                .map_err(|_| ProcessMessageError::Unsupported)?,
-                        )
-                        .map_err(|_| ProcessMessageError::Unsupported)?
-                        .into_location();
-
-                        // ↑↑ NEW CODE ↑↑
-                    }
-                    DescendOrigin(j) => {
-                        let Ok(_) = actual_origin.append_with(*j) else {
-                            return Err(ProcessMessageError::Unsupported);
-                        };
-                    }
-                    _ => return Ok(ControlFlow::Break(())),
-                };
-                skipped.set(skipped.get() + 1);
-                Ok(ControlFlow::Continue(()))
-            },
-        )?;
+        let (actual_origin, skipped) =
+            compute_actual_origin(origin, instructions, LocalUniversal::get(), MaxPrefixes::get())?;
         InnerBarrier::should_execute(
             &actual_origin,
-            &mut instructions[skipped.get()..],
+            &mut instructions[skipped..],
             max_weight,
             properties,
         )
     }
 }
 
+/// Replays the leading `UniversalOrigin`/`DescendOrigin` instructions of a message against
+/// `origin`, folding each `UniversalOrigin` hop through `local_universal` in turn. Bridged
+/// messages may carry more than one `UniversalOrigin` (one per consensus hop), so this composes
+/// left-to-right rather than assuming a single hop.
+///
+/// Returns the resulting origin together with the number of leading instructions consumed.
+pub(crate) fn compute_actual_origin<Call>(
+    origin: &MultiLocation,
+    instructions: &mut [Instruction<Call>],
+    local_universal: InteriorMultiLocation,
+    max_prefixes: u32,
+) -> Result<(MultiLocation, usize), ProcessMessageError> {
+    let mut actual_origin = *origin;
+    let skipped = Cell::new(0usize);
+    instructions.matcher().match_next_inst_while(
+        |_| skipped.get() < max_prefixes as usize,
+        |inst| {
+            match inst {
+                UniversalOrigin(new_global) => {
+                    // ↓↓ ORIGINAL CODE ↓↓
+
+                    // Note the origin is *relative to local consensus*! So we need to escape
+                    // local consensus with the `parents` before diving in into the
+                    // `universal_location`.
+                    // actual_origin = X1(*new_global).relative_to(&LocalUniversal::get());
+
+                    // ↑↑ ORIGINAL CODE ↑↑
+
+                    // ↓↓ NEW CODE ↓↓
+
+                    actual_origin = X1(GlobalConsensus(
+                        local_universal
+                            .global_consensus()
+                            .map_err(|_| ProcessMessageError::Unsupported)?,
+                    ))
+                    .within_global(
+                        actual_origin
+                            .prepended_with(local_universal.relative_to(&X1(*new_global)))
+                            .map_err(|_| ProcessMessageError::Unsupported)?,
+                    )
+                    .map_err(|_| ProcessMessageError::Unsupported)?
+                    .into_location();
+
+                    // ↑↑ NEW CODE ↑↑
+                }
+                DescendOrigin(j) => {
+                    let Ok(_) = actual_origin.append_with(*j) else {
+                        return Err(ProcessMessageError::Unsupported);
+                    };
+                }
+                _ => return Ok(ControlFlow::Break(())),
+            };
+            skipped.set(skipped.get() + 1);
+            Ok(ControlFlow::Continue(()))
+        },
+    )?;
+    Ok((actual_origin, skipped.get()))
+}
+
 pub struct NewDescribeFamily<DescribeInterior>(PhantomData<DescribeInterior>);
 impl<Suffix: DescribeLocation> DescribeLocation for NewDescribeFamily<Suffix> {
     fn describe_location(l: &MultiLocation) -> Option<Vec<u8>> {
@@ -98,6 +115,49 @@ impl<Suffix: DescribeLocation> DescribeLocation for NewDescribeFamily<Suffix> {
                 let interior = Suffix::describe_location(&tail.into())?;
                 Some((b"SiblingChain", Compact::<u32>::from(*index), interior).encode())
             }
+            // ↓↓ NEW CODE ↓↓
+            (parents, Some(GlobalConsensus(network_id))) if parents >= 1 => {
+                let tail = l.interior.split_first().0;
+                match tail.first() {
+                    Some(Parachain(index)) => {
+                        let tail = tail.split_first().0;
+                        let interior = Suffix::describe_location(&tail.into())?;
+                        Some(
+                            (
+                                b"RemoteUniversalLocation",
+                                Compact::<u32>::from(parents as u32),
+                                *network_id,
+                                b"Parachain",
+                                Compact::<u32>::from(*index),
+                                interior,
+                            )
+                                .encode(),
+                        )
+                    }
+                    None => Some(
+                        (
+                            b"RemoteUniversalLocation",
+                            Compact::<u32>::from(parents as u32),
+                            *network_id,
+                        )
+                            .encode(),
+                    ),
+                    Some(_) => {
+                        let interior = Suffix::describe_location(&tail.into())?;
+                        Some(
+                            (
+                                b"RemoteUniversalLocation",
+                                Compact::<u32>::from(parents as u32),
+                                *network_id,
+                                interior,
+                            )
+                                .encode(),
+                        )
+                    }
+                }
+            }
+            // ↑↑ NEW CODE ↑↑
+
             (1, _) => {
                 let tail = l.interior.into();
                 let interior = Suffix::describe_location(&tail)?;
@@ -122,7 +182,11 @@ impl<Suffix: DescribeLocation> DescribeLocation for NewDescribeFamily<Suffix> {
                                 .encode(),
                         )
                     }
-                    _ => return None,
+                    None => Some((b"UniversalLocation", *network_id).encode()),
+                    Some(_) => {
+                        let interior = Suffix::describe_location(&tail.into())?;
+                        Some((b"UniversalLocation", *network_id, interior).encode())
+                    }
                 }
             }
             // ↑↑ NEW CODE ↑↑
@@ -145,8 +209,75 @@ pub type ParaBarrier =
 pub type LegacyBarrier =
     WithComputedOrigin<LegacyDeriveAccountBarrier, RelayUniversalLocation, ConstU32<8>>;
 
-pub struct DeriveAccountBarrier;
-impl ShouldExecute for DeriveAccountBarrier {
+pub type RelayBarrierWithTopic = TrailingSetTopicAsId<RelayBarrier>;
+
+pub type ParaBarrierWithTopic = TrailingSetTopicAsId<ParaBarrier>;
+
+/// Wraps `InnerBarrier`, and additionally records the message's `SetTopic` (if it ends with one)
+/// as `Properties.message_id`, so downstream consumers can correlate the derived origin with a
+/// topic for tracing or dedup.
+pub struct TrailingSetTopicAsId<InnerBarrier>(PhantomData<InnerBarrier>);
+impl<InnerBarrier: ShouldExecute> ShouldExecute for TrailingSetTopicAsId<InnerBarrier> {
+    fn should_execute<Call>(
+        origin: &MultiLocation,
+        instructions: &mut [Instruction<Call>],
+        max_weight: Weight,
+        properties: &mut Properties,
+    ) -> Result<(), ProcessMessageError> {
+        InnerBarrier::should_execute(origin, instructions, max_weight, properties)?;
+        if let Some(SetTopic(topic)) = instructions.last() {
+            properties.message_id = Some(*topic);
+        }
+        Ok(())
+    }
+}
+
+/// Converts `GlobalConsensus(net)/AccountId32 { id, .. }` (optionally one hop down into a
+/// `Parachain`) into `id` directly, for any `net` accepted by `AllowedNetworks`. This is the
+/// "alias" counterpart to `HashedDescription`: trusted networks keep the *same* 32-byte account
+/// on both sides instead of deriving an opaque sovereign one. Anything else falls back to
+/// `Fallback`.
+pub struct AliasAccountId32<AllowedNetworks, Fallback>(PhantomData<(AllowedNetworks, Fallback)>);
+impl<AllowedNetworks, Fallback> ConvertLocation<AccountId32>
+    for AliasAccountId32<AllowedNetworks, Fallback>
+where
+    AllowedNetworks: Contains<NetworkId>,
+    Fallback: ConvertLocation<AccountId32>,
+{
+    fn convert_location(location: &MultiLocation) -> Option<AccountId32> {
+        match location.interior {
+            X2(GlobalConsensus(network), Junction::AccountId32 { id, .. })
+                if location.parents == 0 && AllowedNetworks::contains(&network) =>
+            {
+                Some(id.into())
+            }
+            X3(GlobalConsensus(network), Parachain(_), Junction::AccountId32 { id, .. })
+                if location.parents == 0 && AllowedNetworks::contains(&network) =>
+            {
+                Some(id.into())
+            }
+            _ => Fallback::convert_location(location),
+        }
+    }
+}
+
+pub struct TrustedNetworks;
+impl Contains<NetworkId> for TrustedNetworks {
+    fn contains(network: &NetworkId) -> bool {
+        matches!(network, NetworkId::Kusama | NetworkId::Polkadot)
+    }
+}
+
+pub type TrustedAliasOrHashedDescription = AliasAccountId32<
+    TrustedNetworks,
+    HashedDescription<AccountId32, NewDescribeFamily<DescribeAllTerminal>>,
+>;
+
+pub type AliasingRelayBarrier =
+    NewWithComputedOrigin<AliasingDeriveAccountBarrier, RelayUniversalLocation, ConstU32<8>>;
+
+pub struct AliasingDeriveAccountBarrier;
+impl ShouldExecute for AliasingDeriveAccountBarrier {
     fn should_execute<Call>(
         origin: &MultiLocation,
         _instructions: &mut [Instruction<Call>],
@@ -155,10 +286,7 @@ impl ShouldExecute for DeriveAccountBarrier {
     ) -> Result<(), ProcessMessageError> {
         eprintln!("origin: {:?}", origin);
 
-        let account =
-            HashedDescription::<AccountId32, NewDescribeFamily<DescribeAllTerminal>>::convert_location(
-                origin,
-            ).unwrap();
+        let account = TrustedAliasOrHashedDescription::convert_location(origin).unwrap();
 
         eprintln!("account: {:?}", account);
 
@@ -166,6 +294,36 @@ impl ShouldExecute for DeriveAccountBarrier {
     }
 }
 
+/// Derives the sovereign account for `origin` the post-RFC34 way: `NewDescribeFamily` followed
+/// by a blake2-256 hash. Shared by [`DeriveAccountBarrier`] and the regression tests below so
+/// both exercise the exact same code path.
+pub(crate) fn derive_account(origin: &MultiLocation) -> AccountId32 {
+    HashedDescription::<AccountId32, NewDescribeFamily<DescribeAllTerminal>>::convert_location(
+        origin,
+    )
+    .expect("NewDescribeFamily describes any location reachable via UniversalOrigin/DescendOrigin")
+}
+
+/// Derives the sovereign account the legacy way, for comparison against [`derive_account`].
+pub(crate) fn legacy_derive_account(origin: &MultiLocation) -> AccountId32 {
+    HashedDescription::<AccountId32, DescribeFamily<DescribeAllTerminal>>::convert_location(origin)
+        .expect("DescribeFamily describes this location")
+}
+
+pub struct DeriveAccountBarrier;
+impl ShouldExecute for DeriveAccountBarrier {
+    fn should_execute<Call>(
+        origin: &MultiLocation,
+        _instructions: &mut [Instruction<Call>],
+        _max_weight: Weight,
+        _properties: &mut Properties,
+    ) -> Result<(), ProcessMessageError> {
+        eprintln!("origin: {:?}", origin);
+        eprintln!("account: {:?}", derive_account(origin));
+        Ok(())
+    }
+}
+
 pub struct LegacyDeriveAccountBarrier;
 impl ShouldExecute for LegacyDeriveAccountBarrier {
     fn should_execute<Call>(
@@ -175,14 +333,7 @@ impl ShouldExecute for LegacyDeriveAccountBarrier {
         _properties: &mut Properties,
     ) -> Result<(), ProcessMessageError> {
         eprintln!("origin: {:?}", origin);
-
-        let account =
-            HashedDescription::<AccountId32, DescribeFamily<DescribeAllTerminal>>::convert_location(
-                origin,
-            ).unwrap();
-
-        eprintln!("account: {:?}", account);
-
+        eprintln!("account: {:?}", legacy_derive_account(origin));
         Ok(())
     }
 }
@@ -237,6 +388,168 @@ fn main() {
     )
     .unwrap();
 
+    eprintln!();
+    eprintln!("Multi-hop bridged origin (two UniversalOrigin hops):");
+
+    let mut instructions_bridged: Vec<Instruction<()>> = vec![
+        Instruction::UniversalOrigin(Junction::GlobalConsensus(NetworkId::Polkadot)),
+        Instruction::UniversalOrigin(Junction::GlobalConsensus(NetworkId::Ethereum { chain_id: 1 })),
+        Instruction::Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(0, 0),
+            call: <DoubleEncoded<()> as From<Vec<u8>>>::from(Vec::<u8>::new()),
+        },
+    ];
+
+    // The barrier's own computation of the final origin, exactly as `NewWithComputedOrigin`
+    // derives it while walking the leading instructions. See `mod tests` for the assertions
+    // this is expected to satisfy.
+    compute_actual_origin(
+        &origin_from_relay_perspective,
+        &mut instructions_bridged.clone(),
+        RelayUniversalLocation::get(),
+        ConstU32::<8>::get(),
+    )
+    .unwrap();
+
+    // The barrier derives its account from exactly the origin `compute_actual_origin` returns
+    // above, so running it end-to-end must not error for the same instructions.
+    <RelayBarrier as ShouldExecute>::should_execute(
+        &origin_from_relay_perspective,
+        &mut instructions_bridged,
+        Weight::from_parts(100, 100),
+        &mut Properties {
+            weight_credit: Weight::from_parts(100, 100),
+            message_id: None,
+        },
+    )
+    .unwrap();
+
+    eprintln!();
+    eprintln!("Message ending in SetTopic populates Properties.message_id:");
+
+    let topic = [7u8; 32];
+    let mut instructions_with_topic: Vec<Instruction<()>> = vec![
+        Instruction::Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(0, 0),
+            call: <DoubleEncoded<()> as From<Vec<u8>>>::from(Vec::<u8>::new()),
+        },
+        Instruction::SetTopic(topic),
+    ];
+
+    let mut properties_with_topic = Properties {
+        weight_credit: Weight::from_parts(100, 100),
+        message_id: None,
+    };
+    <RelayBarrierWithTopic as ShouldExecute>::should_execute(
+        &origin_from_relay_perspective,
+        &mut instructions_with_topic,
+        Weight::from_parts(100, 100),
+        &mut properties_with_topic,
+    )
+    .unwrap();
+
+    let mut properties_without_topic_wrapper = Properties {
+        weight_credit: Weight::from_parts(100, 100),
+        message_id: None,
+    };
+    <RelayBarrier as ShouldExecute>::should_execute(
+        &origin_from_relay_perspective,
+        &mut instructions_with_topic,
+        Weight::from_parts(100, 100),
+        &mut properties_without_topic_wrapper,
+    )
+    .unwrap();
+
+    eprintln!();
+    eprintln!("Para-perspective message ending in SetTopic populates Properties.message_id:");
+
+    let mut instructions_with_topic_para: Vec<Instruction<()>> = vec![
+        Instruction::Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(0, 0),
+            call: <DoubleEncoded<()> as From<Vec<u8>>>::from(Vec::<u8>::new()),
+        },
+        Instruction::SetTopic(topic),
+    ];
+
+    let mut properties_with_topic_para = Properties {
+        weight_credit: Weight::from_parts(100, 100),
+        message_id: None,
+    };
+    <ParaBarrierWithTopic as ShouldExecute>::should_execute(
+        &origin_from_para_perspective,
+        &mut instructions_with_topic_para,
+        Weight::from_parts(100, 100),
+        &mut properties_with_topic_para,
+    )
+    .unwrap();
+
+    eprintln!();
+    eprintln!("Aliasing a trusted GlobalConsensus AccountId32 instead of hashing it:");
+
+    let trusted_account_id = [9u8; 32];
+    let trusted_origin = MultiLocation {
+        parents: 0,
+        interior: X2(
+            GlobalConsensus(NetworkId::Kusama),
+            Junction::AccountId32 {
+                network: None,
+                id: trusted_account_id,
+            },
+        ),
+    };
+    eprintln!(
+        "account: {:?}",
+        TrustedAliasOrHashedDescription::convert_location(&trusted_origin),
+    );
+
+    let untrusted_account_id = [9u8; 32];
+    let untrusted_origin = MultiLocation {
+        parents: 0,
+        interior: X2(
+            GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+            Junction::AccountId32 {
+                network: None,
+                id: untrusted_account_id,
+            },
+        ),
+    };
+    eprintln!(
+        "account: {:?}",
+        TrustedAliasOrHashedDescription::convert_location(&untrusted_origin),
+    );
+
+    eprintln!();
+    eprintln!("Same aliasing, but through the AliasingRelayBarrier plumbing:");
+
+    let bare_kusama_origin = MultiLocation {
+        parents: 0,
+        interior: X1(GlobalConsensus(NetworkId::Kusama)),
+    };
+    let mut instructions_descend_to_trusted_account: Vec<Instruction<()>> = vec![
+        Instruction::DescendOrigin(Junctions::X1(Junction::AccountId32 {
+            network: None,
+            id: trusted_account_id,
+        })),
+        Instruction::Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(0, 0),
+            call: <DoubleEncoded<()> as From<Vec<u8>>>::from(Vec::<u8>::new()),
+        },
+    ];
+    <AliasingRelayBarrier as ShouldExecute>::should_execute(
+        &bare_kusama_origin,
+        &mut instructions_descend_to_trusted_account,
+        Weight::from_parts(100, 100),
+        &mut Properties {
+            weight_credit: Weight::from_parts(100, 100),
+            message_id: None,
+        },
+    )
+    .unwrap();
+
     eprintln!();
     eprintln!("Proof the changes won't break \"legacy\":");
 
@@ -345,3 +658,353 @@ fn main() {
     )
     .unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<B: ShouldExecute>(
+        origin: &MultiLocation,
+        instructions: &mut [Instruction<()>],
+    ) -> Properties {
+        let mut properties = Properties {
+            weight_credit: Weight::from_parts(100, 100),
+            message_id: None,
+        };
+        B::should_execute(origin, instructions, Weight::from_parts(100, 100), &mut properties)
+            .unwrap();
+        properties
+    }
+
+    fn transact() -> Instruction<()> {
+        Instruction::Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: Weight::from_parts(0, 0),
+            call: <DoubleEncoded<()> as From<Vec<u8>>>::from(Vec::<u8>::new()),
+        }
+    }
+
+    #[test]
+    fn relay_and_para_perspective_agree_on_the_same_absolute_origin() {
+        // A message claiming `UniversalOrigin(Kusama)` (the local consensus) resolves to the same
+        // absolute `GlobalConsensus(Kusama)/Parachain(2125)` origin, whichever vantage point it's
+        // evaluated from.
+        let relay_origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let para_origin = MultiLocation {
+            parents: 1,
+            interior: X1(Parachain(2125)),
+        };
+        let mut relay_instructions = vec![
+            Instruction::UniversalOrigin(GlobalConsensus(NetworkId::Kusama)),
+            Instruction::DescendOrigin(X1(Plurality {
+                id: BodyId::Index(0),
+                part: BodyPart::Voice,
+            })),
+            transact(),
+        ];
+        let mut para_instructions = relay_instructions.clone();
+
+        let (relay_actual_origin, relay_skipped) = compute_actual_origin(
+            &relay_origin,
+            &mut relay_instructions,
+            RelayUniversalLocation::get(),
+            ConstU32::<8>::get(),
+        )
+        .unwrap();
+        let (para_actual_origin, para_skipped) = compute_actual_origin(
+            &para_origin,
+            &mut para_instructions,
+            ParaUniversalLocation::get(),
+            ConstU32::<8>::get(),
+        )
+        .unwrap();
+
+        assert_eq!(relay_skipped, 2);
+        assert_eq!(para_skipped, 2);
+        assert_eq!(relay_actual_origin.parents, 0);
+        assert_eq!(para_actual_origin.parents, 0);
+        assert_eq!(derive_account(&relay_actual_origin), derive_account(&para_actual_origin));
+    }
+
+    #[test]
+    fn descend_to_account_id_matches_golden_value() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let mut instructions = vec![
+            Instruction::DescendOrigin(X1(Junction::AccountId32 {
+                network: None,
+                id: [2u8; 32],
+            })),
+            transact(),
+        ];
+        let (actual_origin, skipped) =
+            compute_actual_origin(&origin, &mut instructions, RelayUniversalLocation::get(), 8)
+                .unwrap();
+
+        assert_eq!(skipped, 1);
+        assert_eq!(
+            actual_origin,
+            MultiLocation {
+                parents: 0,
+                interior: X2(
+                    Parachain(2125),
+                    Junction::AccountId32 {
+                        network: None,
+                        id: [2u8; 32],
+                    }
+                ),
+            }
+        );
+        assert_eq!(
+            derive_account(&actual_origin),
+            AccountId32::from([
+                0xe9, 0x8c, 0xc9, 0x9b, 0x08, 0xe4, 0xc6, 0xbd, 0x7b, 0x4a, 0x33, 0x98, 0xf0,
+                0x95, 0xf0, 0x77, 0xf1, 0xf2, 0xe7, 0x7b, 0xb1, 0xa7, 0xc7, 0x55, 0xc9, 0xee,
+                0xb5, 0xab, 0x63, 0x7e, 0x89, 0x59,
+            ]),
+        );
+        // `DescendOrigin` alone (no `UniversalOrigin`) is unaffected by RFC-34: legacy and
+        // post-RFC34 derivation agree.
+        assert_eq!(derive_account(&actual_origin), legacy_derive_account(&actual_origin));
+    }
+
+    #[test]
+    fn descend_to_pallet_matches_golden_value() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let mut instructions = vec![
+            Instruction::DescendOrigin(X1(Junction::PalletInstance(42))),
+            transact(),
+        ];
+        let (actual_origin, skipped) =
+            compute_actual_origin(&origin, &mut instructions, RelayUniversalLocation::get(), 8)
+                .unwrap();
+
+        assert_eq!(skipped, 1);
+        assert_eq!(
+            actual_origin,
+            MultiLocation {
+                parents: 0,
+                interior: X2(Parachain(2125), Junction::PalletInstance(42)),
+            }
+        );
+        assert_eq!(
+            derive_account(&actual_origin),
+            AccountId32::from([
+                0x1b, 0x0e, 0x05, 0x67, 0xdc, 0xcd, 0x3b, 0x2a, 0x29, 0xd3, 0xe5, 0x2b, 0x33,
+                0xfc, 0x71, 0x7c, 0x40, 0x55, 0x9d, 0x7e, 0xe3, 0x5b, 0x73, 0xbb, 0xc0, 0x50,
+                0xf9, 0x80, 0x39, 0xea, 0x3c, 0x9f,
+            ]),
+        );
+        assert_eq!(derive_account(&actual_origin), legacy_derive_account(&actual_origin));
+    }
+
+    #[test]
+    fn terminal_origin_matches_golden_value() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let mut instructions = vec![transact()];
+        let (actual_origin, skipped) =
+            compute_actual_origin(&origin, &mut instructions, RelayUniversalLocation::get(), 8)
+                .unwrap();
+
+        assert_eq!(skipped, 0);
+        assert_eq!(actual_origin, origin);
+        assert_eq!(
+            derive_account(&actual_origin),
+            AccountId32::from([
+                0xc7, 0xe2, 0xb9, 0xed, 0xc9, 0x4e, 0x50, 0xb0, 0xe6, 0x5e, 0xbf, 0xc9, 0x9b,
+                0xf8, 0xc7, 0xa2, 0x54, 0xe2, 0xd2, 0x5a, 0xc5, 0x31, 0x15, 0x6d, 0xf1, 0xb7,
+                0x64, 0x6c, 0xa0, 0x3c, 0x17, 0x3f,
+            ]),
+        );
+        assert_eq!(derive_account(&actual_origin), legacy_derive_account(&actual_origin));
+    }
+
+    #[test]
+    fn bare_universal_location_matches_golden_value() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(GlobalConsensus(NetworkId::Polkadot)),
+        };
+        assert_eq!(
+            derive_account(&origin),
+            AccountId32::from([
+                0xb9, 0x34, 0xdb, 0x0d, 0x45, 0xaf, 0x2d, 0x19, 0x45, 0x57, 0x5b, 0x6c, 0xe4,
+                0xe5, 0x1f, 0x4c, 0x39, 0x38, 0x34, 0x38, 0xaf, 0x39, 0x12, 0x1c, 0x96, 0x3a,
+                0x1a, 0xed, 0xcf, 0xfa, 0xa0, 0x4b,
+            ]),
+        );
+        // The legacy describer has no `GlobalConsensus` arm at all.
+        assert!(
+            HashedDescription::<AccountId32, DescribeFamily<DescribeAllTerminal>>::convert_location(
+                &origin
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn universal_account_id_matches_golden_value() {
+        // A bridged EOA reached directly (no Parachain hop): GlobalConsensus(net)/AccountId32.
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X2(
+                GlobalConsensus(NetworkId::Polkadot),
+                Junction::AccountId32 {
+                    network: None,
+                    id: [2u8; 32],
+                },
+            ),
+        };
+        assert_eq!(
+            derive_account(&origin),
+            AccountId32::from([
+                0x3b, 0x16, 0x43, 0x93, 0xbc, 0xe3, 0x37, 0x8f, 0x77, 0x02, 0x95, 0x7b, 0x58,
+                0xa3, 0x9c, 0x19, 0x50, 0x0d, 0x4a, 0x8c, 0xf5, 0x8d, 0xb3, 0x9c, 0xb5, 0x89,
+                0xf5, 0xf6, 0x33, 0x04, 0xae, 0x6b,
+            ]),
+        );
+    }
+
+    #[test]
+    fn remote_universal_location_matches_golden_value() {
+        // A location reached across a bridge hub, expressed with `parents >= 1`: this is the
+        // shape the multi-hop `UniversalOrigin` computation can land on.
+        let origin = MultiLocation {
+            parents: 1,
+            interior: X1(GlobalConsensus(NetworkId::Polkadot)),
+        };
+        assert_eq!(
+            derive_account(&origin),
+            AccountId32::from([
+                0x2a, 0xc7, 0xfb, 0x6d, 0x88, 0xaf, 0x4e, 0xd7, 0x3e, 0xae, 0x4d, 0xe3, 0x50,
+                0xe0, 0x2d, 0xa1, 0x89, 0x51, 0x63, 0xed, 0xe7, 0xa8, 0xd9, 0x38, 0xaa, 0x34,
+                0xf4, 0x8a, 0xed, 0x65, 0x4c, 0xf0,
+            ]),
+        );
+    }
+
+    #[test]
+    fn remote_universal_location_with_parachain_matches_golden_value() {
+        // The motivating shape for the `parents >= 1` `GlobalConsensus` arm: a bridged chain's
+        // own parachain, not just its bare relay consensus.
+        let origin = MultiLocation {
+            parents: 1,
+            interior: X3(
+                GlobalConsensus(NetworkId::Polkadot),
+                Parachain(2000),
+                Junction::AccountId32 {
+                    network: None,
+                    id: [5u8; 32],
+                },
+            ),
+        };
+        assert_eq!(
+            derive_account(&origin),
+            AccountId32::from([
+                0xd7, 0x57, 0x10, 0x8b, 0xca, 0xbd, 0x68, 0xd2, 0xe2, 0x87, 0xb9, 0xbd, 0xf3,
+                0x40, 0x0c, 0x6f, 0x34, 0x1d, 0x51, 0x26, 0xd2, 0xd5, 0xc0, 0x42, 0xad, 0xdc,
+                0x01, 0xc3, 0x5e, 0x65, 0xe6, 0xab,
+            ]),
+        );
+    }
+
+    #[test]
+    fn bridged_two_hop_origin_is_consumed_and_describable() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let mut instructions = vec![
+            Instruction::UniversalOrigin(GlobalConsensus(NetworkId::Polkadot)),
+            Instruction::UniversalOrigin(GlobalConsensus(NetworkId::Ethereum { chain_id: 1 })),
+            transact(),
+        ];
+        let (actual_origin, skipped) =
+            compute_actual_origin(&origin, &mut instructions, RelayUniversalLocation::get(), 8)
+                .unwrap();
+
+        assert_eq!(skipped, 2);
+        // Each `UniversalOrigin` hop only ever prepends ahead of what's already there, so the
+        // original interior (`Parachain(2125)`) must still be the innermost junction, however
+        // many hops were folded in front of it.
+        assert_eq!(actual_origin.interior.last(), Some(&Parachain(2125)));
+        assert!(NewDescribeFamily::<DescribeAllTerminal>::describe_location(&actual_origin).is_some());
+    }
+
+    #[test]
+    fn trailing_set_topic_populates_message_id_only_when_wrapped() {
+        let origin = MultiLocation {
+            parents: 0,
+            interior: X1(Parachain(2125)),
+        };
+        let topic = [7u8; 32];
+
+        let with_wrapper = run::<RelayBarrierWithTopic>(
+            &origin,
+            &mut [transact(), Instruction::SetTopic(topic)],
+        );
+        assert_eq!(with_wrapper.message_id, Some(topic));
+
+        let without_wrapper =
+            run::<RelayBarrier>(&origin, &mut [transact(), Instruction::SetTopic(topic)]);
+        assert_eq!(without_wrapper.message_id, None);
+    }
+
+    #[test]
+    fn trailing_set_topic_populates_message_id_from_para_perspective_too() {
+        let origin = MultiLocation {
+            parents: 1,
+            interior: X1(Parachain(2125)),
+        };
+        let topic = [7u8; 32];
+
+        let with_wrapper = run::<ParaBarrierWithTopic>(
+            &origin,
+            &mut [transact(), Instruction::SetTopic(topic)],
+        );
+        assert_eq!(with_wrapper.message_id, Some(topic));
+    }
+
+    #[test]
+    fn aliasing_trusted_network_returns_the_account_id_unhashed() {
+        let id = [9u8; 32];
+        let trusted = MultiLocation {
+            parents: 0,
+            interior: X2(
+                GlobalConsensus(NetworkId::Kusama),
+                Junction::AccountId32 { network: None, id },
+            ),
+        };
+        assert_eq!(
+            TrustedAliasOrHashedDescription::convert_location(&trusted),
+            Some(AccountId32::from(id)),
+        );
+    }
+
+    #[test]
+    fn aliasing_untrusted_network_falls_back_to_hashing() {
+        let id = [9u8; 32];
+        let untrusted = MultiLocation {
+            parents: 0,
+            interior: X2(
+                GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+                Junction::AccountId32 { network: None, id },
+            ),
+        };
+        assert_eq!(
+            TrustedAliasOrHashedDescription::convert_location(&untrusted),
+            derive_account(&untrusted).into(),
+        );
+    }
+}